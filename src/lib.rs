@@ -1,14 +1,24 @@
 use bevy_app::prelude::Plugin;
 use bevy_asset::{load_internal_asset, prelude::Assets, Asset, Handle};
 use bevy_color::{Color, LinearRgba};
-use bevy_ecs::prelude::{Bundle, Component, Query, ResMut};
+use bevy_ecs::prelude::{
+    Added, Bundle, Commands, Component, Entity, IntoSystemConfigs, Query, Res, ResMut,
+};
+use bevy_image::Image;
+use bevy_math::{Vec2, Vec4};
 use bevy_reflect::TypePath;
 use bevy_render::{
     render_resource::{AsBindGroup, Shader},
     storage::ShaderStorageBuffer,
 };
-use bevy_ui::{MaterialNode, Node, UiMaterial, UiMaterialPlugin};
+use bevy_text::{JustifyText, Text, TextLayout};
+use bevy_time::Time;
+use bevy_ui::{
+    AlignItems, ComputedNode, Display, JustifyContent, MaterialNode, Node, PositionType,
+    UiMaterial, UiMaterialPlugin,
+};
 use bevy_utils::default;
+use core::time::Duration;
 
 pub const PROGRESS_BAR_HANDLE: Handle<Shader> =
     Handle::weak_from_u128(8714649747086695632918559878778085427);
@@ -22,24 +32,93 @@ impl Plugin for ProgressBarPlugin {
             "progress_shader.wgsl",
             Shader::from_wgsl
         );
-        app.add_systems(bevy_app::Update, update_progress_bar)
-            .add_plugins(UiMaterialPlugin::<ProgressBarMaterial>::default());
+        app.add_systems(
+            bevy_app::Update,
+            (
+                update_progress_bar,
+                setup_progress_bar_label,
+                update_progress_bar_label,
+                update_progress_animation.after(update_progress_bar),
+            ),
+        )
+        .add_plugins(UiMaterialPlugin::<ProgressBarMaterial>::default());
     }
 }
 
 /// The Progress Bar.
 /// Has Different Colored section with relative size to each other
 /// and a Color for the empty space
+///
+/// Internally the bar tracks a `value` within `[min, max]`, defaulting to the
+/// normalized `[0.0, 1.0]` range used by `set_progress`/`get_progress`. Use
+/// [`ProgressBar::ranged`] when the quantity you're tracking is more natural
+/// to express as e.g. `37.0 / 150.0` HP than as a pre-normalized fraction.
 #[derive(Component, Clone)]
 pub struct ProgressBar {
-    /// The Progress
-    /// a f32 between 0.0 and 1.0
-    progress: f32,
+    /// The lower bound of `value`
+    min: f32,
+    /// The upper bound of `value`
+    max: f32,
+    /// The current value, always within `[min, max]`
+    value: f32,
+    /// If greater than 0.0, `value` is snapped to the nearest multiple of
+    /// `step` (relative to `min`) whenever it is set
+    step: f32,
     /// The Different Sections
     /// The amount is the space relative to the other Sections.
     pub sections: Vec<(u32, Color)>,
     /// The Color of the space that is not progressed to
     pub empty_color: Color,
+    /// The axis and origin the bar fills from
+    pub fill_direction: FillDirection,
+    /// Background texture drawn behind the empty space, stretched over the
+    /// whole node. Falls back to `empty_color` when `None`.
+    pub background_image: Option<Handle<Image>>,
+    /// Foreground texture drawn over the filled portion, stretched over the
+    /// whole node. Falls back to the section colors when `None`.
+    pub foreground_image: Option<Handle<Image>>,
+    /// Border insets in UV space, as `(left, right, top, bottom)`. Each inset
+    /// maps onto the same fraction of the texture, so the texture's edges and
+    /// corners scale with the node just like its middle; they are not kept at
+    /// a fixed size.
+    pub border: Vec4,
+    /// Radius of the rounded corners, in logical pixels. `0.0` is a plain rect
+    pub corner_radius: f32,
+    /// Color of the outline drawn `border_width` pixels inside the rounded rect
+    pub border_color: Color,
+    /// Width of the outline, in logical pixels. `0.0` draws no outline
+    pub border_width: f32,
+    /// Intensity of an emissive "charging" falloff near the filled edge.
+    /// `0.0` disables the glow
+    pub glow: f32,
+}
+
+/// The axis and origin a [`ProgressBar`] fills from.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FillDirection {
+    /// Fills across the node from left to right (the default)
+    #[default]
+    LeftToRight,
+    /// Fills across the node from right to left
+    RightToLeft,
+    /// Fills up the node from bottom to top
+    BottomToTop,
+    /// Fills down the node from top to bottom
+    TopToBottom,
+    /// Fills outward from the center of the node in both directions
+    CenterOut,
+}
+
+impl FillDirection {
+    fn as_shader_index(self) -> u32 {
+        match self {
+            FillDirection::LeftToRight => 0,
+            FillDirection::RightToLeft => 1,
+            FillDirection::BottomToTop => 2,
+            FillDirection::TopToBottom => 3,
+            FillDirection::CenterOut => 4,
+        }
+    }
 }
 
 impl ProgressBar {
@@ -53,20 +132,47 @@ impl ProgressBar {
     /// ```
     pub fn new(sections: Vec<(u32, Color)>) -> Self {
         Self {
-            progress: 0.0,
             sections,
-            empty_color: Color::NONE,
+            ..Self::default()
         }
     }
     /// Creates a new ProgressBar with a single section
     pub fn single(color: Color) -> Self {
         Self {
-            progress: 0.0,
             sections: vec![(1, color)],
-            empty_color: Color::NONE,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new ProgressBar tracking an arbitrary value range, e.g.
+    /// `ProgressBar::ranged(0.0, 150.0)` for an HP bar. `value` starts at
+    /// `min` and `get_progress()` keeps returning the normalized fraction
+    /// fed to the shader.
+    ///
+    /// # Examples
+    /// ```
+    /// use bevy_progressbar::ProgressBar;
+    /// let mut bar = ProgressBar::ranged(0.0, 150.0);
+    /// bar.set_value(37.0);
+    /// assert_eq!(bar.value(), 37.0);
+    /// assert!((bar.get_progress() - 37.0 / 150.0).abs() < f32::EPSILON);
+    /// ```
+    pub fn ranged(min: f32, max: f32) -> Self {
+        Self {
+            min,
+            max,
+            value: min,
+            ..Self::default()
         }
     }
 
+    /// Sets the step used to snap `value` to the nearest multiple (relative
+    /// to `min`) on every future `set_value`/`increment`/`decrement` call.
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
     /// Sets the progress of the bar
     ///
     /// # Arguments
@@ -85,13 +191,17 @@ impl ProgressBar {
     /// assert_eq!(bar.get_progress(), 1.0);
     /// ```
     pub fn set_progress(&mut self, amount: f32) -> &mut Self {
-        self.progress = amount.clamp(0.0, 1.0);
-        self
+        self.set_value(self.min + amount.clamp(0.0, 1.0) * (self.max - self.min))
     }
 
-    /// Returns the current progress
+    /// Returns the current progress as a normalized fraction of `[min, max]`.
+    /// This is the value fed to the shader's `progress` uniform.
     pub fn get_progress(&self) -> f32 {
-        self.progress
+        if self.max > self.min {
+            (self.value - self.min) / (self.max - self.min)
+        } else {
+            0.0
+        }
     }
 
     /// Increases the progress
@@ -107,15 +217,12 @@ impl ProgressBar {
     /// assert_eq!(bar.get_progress(), 1.0);
     /// ```
     pub fn increase_progress(&mut self, amount: f32) -> &mut Self {
-        self.progress += amount;
-        self.progress = self.progress.clamp(0.0, 1.0);
-        self
+        self.set_progress(self.get_progress() + amount)
     }
 
     /// Resets the progress to 0.0
     pub fn reset(&mut self) -> &mut Self {
-        self.progress = 0.0;
-        self
+        self.set_value(self.min)
     }
 
     /// Returns true if the ProgressBar is is_finished
@@ -129,7 +236,7 @@ impl ProgressBar {
     /// assert_eq!(bar.is_finished(), true);
     /// ```
     pub fn is_finished(&self) -> bool {
-        self.progress >= 1.0
+        self.value >= self.max
     }
 
     pub fn clear_sections(&mut self) -> &mut Self {
@@ -141,14 +248,72 @@ impl ProgressBar {
         self.sections.push((amount, color));
         self
     }
+
+    /// Sets the current value, clamping to `[min, max]` and snapping to the
+    /// nearest multiple of `step` (relative to `min`) if `step > 0.0`.
+    pub fn set_value(&mut self, value: f32) -> &mut Self {
+        let mut value = value.clamp(self.min, self.max);
+        if self.step > 0.0 {
+            value = self.min + ((value - self.min) / self.step).round() * self.step;
+            value = value.clamp(self.min, self.max);
+        }
+        self.value = value;
+        self
+    }
+
+    /// Returns the current value within `[min, max]`.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Returns the lower bound of the tracked value.
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Returns the upper bound of the tracked value.
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Increments `value` by one `step` (or by `1.0` if `step` is `0.0`),
+    /// clamped to `max`.
+    pub fn increment(&mut self) -> &mut Self {
+        let step = if self.step > 0.0 { self.step } else { 1.0 };
+        self.set_value(self.value + step)
+    }
+
+    /// Decrements `value` by one `step` (or by `1.0` if `step` is `0.0`),
+    /// clamped to `min`.
+    pub fn decrement(&mut self) -> &mut Self {
+        let step = if self.step > 0.0 { self.step } else { 1.0 };
+        self.set_value(self.value - step)
+    }
+
+    /// Returns the current value as a percentage of `[min, max]`, e.g.
+    /// `42.0` for `42%`.
+    pub fn as_percent(&self) -> f32 {
+        self.get_progress() * 100.0
+    }
 }
 
 impl Default for ProgressBar {
     fn default() -> Self {
         Self {
-            progress: 0.0,
+            min: 0.0,
+            max: 1.0,
+            value: 0.0,
+            step: 0.0,
             sections: vec![],
             empty_color: Color::NONE,
+            fill_direction: FillDirection::default(),
+            background_image: None,
+            foreground_image: None,
+            border: Vec4::ZERO,
+            corner_radius: 0.0,
+            border_color: Color::NONE,
+            border_width: 0.0,
+            glow: 0.0,
         }
     }
 }
@@ -190,6 +355,33 @@ pub struct ProgressBarMaterial {
     sections_start_percentage: Handle<ShaderStorageBuffer>,
     #[uniform(4)]
     sections_count: u32,
+    #[uniform(5)]
+    fill_direction: u32,
+    #[texture(6)]
+    #[sampler(7)]
+    background_image: Option<Handle<Image>>,
+    #[texture(8)]
+    #[sampler(9)]
+    foreground_image: Option<Handle<Image>>,
+    #[uniform(10)]
+    border: Vec4,
+    /// Bitflags: `0b01` if `background_image` is set, `0b10` if
+    /// `foreground_image` is set. WGSL has no `Option`, so this tells the
+    /// shader whether to sample the (otherwise fallback) texture bindings.
+    #[uniform(11)]
+    image_flags: u32,
+    #[uniform(12)]
+    corner_radius: f32,
+    #[uniform(13)]
+    border_color: LinearRgba,
+    #[uniform(14)]
+    border_width: f32,
+    #[uniform(15)]
+    glow: f32,
+    /// The node's rendered size in logical pixels, needed to keep
+    /// `corner_radius`/`border_width` (both in pixels) aspect-correct
+    #[uniform(16)]
+    node_size: Vec2,
 }
 
 impl Default for ProgressBarMaterial {
@@ -200,15 +392,43 @@ impl Default for ProgressBarMaterial {
             sections_color: Handle::default(),
             sections_start_percentage: Handle::default(),
             sections_count: 0,
+            fill_direction: FillDirection::default().as_shader_index(),
+            background_image: None,
+            foreground_image: None,
+            border: Vec4::ZERO,
+            image_flags: 0,
+            corner_radius: 0.0,
+            border_color: LinearRgba::NONE,
+            border_width: 0.0,
+            glow: 0.0,
+            node_size: Vec2::ZERO,
         }
     }
 }
 
 impl ProgressBarMaterial {
-    /// Updates the material to match the ProgressBar
-    pub fn update(&mut self, bar: &ProgressBar, buffers: &mut Assets<ShaderStorageBuffer>) {
+    /// Updates the material to match the ProgressBar. `node_size` is the
+    /// bar node's rendered size in logical pixels, used to keep
+    /// `corner_radius`/`border_width` aspect-correct.
+    pub fn update(
+        &mut self,
+        bar: &ProgressBar,
+        node_size: Vec2,
+        buffers: &mut Assets<ShaderStorageBuffer>,
+    ) {
         self.empty_color = bar.empty_color.to_linear();
-        self.progress = bar.progress;
+        self.progress = bar.get_progress();
+        self.fill_direction = bar.fill_direction.as_shader_index();
+        self.background_image = bar.background_image.clone();
+        self.foreground_image = bar.foreground_image.clone();
+        self.border = bar.border;
+        self.image_flags =
+            bar.background_image.is_some() as u32 | (bar.foreground_image.is_some() as u32) << 1;
+        self.corner_radius = bar.corner_radius;
+        self.border_color = bar.border_color.to_linear();
+        self.border_width = bar.border_width;
+        self.glow = bar.glow;
+        self.node_size = node_size;
 
         let mut colors = Vec::new();
         let mut percentages = Vec::new();
@@ -233,15 +453,287 @@ impl UiMaterial for ProgressBarMaterial {
 }
 
 fn update_progress_bar(
-    bar_query: Query<(&ProgressBar, &MaterialNode<ProgressBarMaterial>)>,
+    bar_query: Query<(
+        &ProgressBar,
+        &ComputedNode,
+        &MaterialNode<ProgressBarMaterial>,
+    )>,
     mut materials: ResMut<Assets<ProgressBarMaterial>>,
     mut buffers: ResMut<Assets<ShaderStorageBuffer>>,
 ) {
-    for (bar, handle) in bar_query.iter() {
+    for (bar, computed_node, handle) in bar_query.iter() {
         let Some(material) = materials.get_mut(handle) else {
             continue;
         };
 
-        material.update(bar, &mut buffers);
+        // `ComputedNode::size` is in physical pixels; the material wants logical ones.
+        let node_size = computed_node.size() * computed_node.inverse_scale_factor();
+        material.update(bar, node_size, &mut buffers);
+    }
+}
+
+/// How a [`ProgressBarLabel`] renders the bar's state as text.
+#[derive(Clone, Copy)]
+pub enum ProgressBarLabelFormat {
+    /// e.g. `"42%"`
+    Percent,
+    /// e.g. `"37/150"`, using the bar's `value()`/`max()`
+    ValueOverMax,
+    /// A caller-provided formatter
+    Custom(fn(&ProgressBar) -> String),
+}
+
+impl ProgressBarLabelFormat {
+    fn format(&self, bar: &ProgressBar) -> String {
+        match self {
+            ProgressBarLabelFormat::Percent => format!("{:.0}%", bar.as_percent()),
+            ProgressBarLabelFormat::ValueOverMax => {
+                format!("{:.0}/{:.0}", bar.value(), bar.max())
+            }
+            ProgressBarLabelFormat::Custom(format) => format(bar),
+        }
+    }
+}
+
+/// Adds a text overlay showing the bar's state, e.g. `"42%"` or `"37/150"`.
+/// Put this on the same entity as a [`ProgressBar`]; `update_progress_bar_label`
+/// spawns and keeps a child `Text` node centered over the bar in sync.
+#[derive(Component)]
+pub struct ProgressBarLabel {
+    pub format: ProgressBarLabelFormat,
+    text_entity: Option<Entity>,
+}
+
+impl ProgressBarLabel {
+    pub fn new(format: ProgressBarLabelFormat) -> Self {
+        Self {
+            format,
+            text_entity: None,
+        }
+    }
+}
+
+// Hand-written: `text_entity` is internal state pointing at this component's
+// own spawned child, so a clone must start fresh rather than pointing two
+// bars at the same text entity (whichever updates last would steal it).
+impl Clone for ProgressBarLabel {
+    fn clone(&self) -> Self {
+        Self {
+            format: self.format,
+            text_entity: None,
+        }
+    }
+}
+
+// Centers the (absolutely positioned) text child within the bar itself;
+// centering properties on the text node would only affect its own children,
+// which it has none of. Runs only on insertion, not every frame, since
+// writing through `&mut Node` always flags it changed even when the value
+// is identical, which would otherwise force a layout recompute every tick.
+fn setup_progress_bar_label(mut bar_query: Query<&mut Node, Added<ProgressBarLabel>>) {
+    for mut node in bar_query.iter_mut() {
+        node.display = Display::Flex;
+        node.align_items = AlignItems::Center;
+        node.justify_content = JustifyContent::Center;
+    }
+}
+
+fn update_progress_bar_label(
+    mut commands: Commands,
+    mut bar_query: Query<(Entity, &ProgressBar, &mut ProgressBarLabel)>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (entity, bar, mut label) in bar_query.iter_mut() {
+        let text = label.format.format(bar);
+
+        if let Some(text_entity) = label.text_entity {
+            if let Ok(mut existing_text) = text_query.get_mut(text_entity) {
+                *existing_text = Text::new(text);
+                continue;
+            }
+        }
+
+        let text_entity = commands
+            .spawn((
+                Text::new(text),
+                TextLayout::new_with_justify(JustifyText::Center),
+                Node {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+            ))
+            .id();
+        commands.entity(entity).add_child(text_entity);
+        label.text_entity = Some(text_entity);
+    }
+}
+
+/// Time constant (in seconds) of the exponential smoothing applied to the
+/// `per_sec()` rate estimate: larger values react more slowly but are less
+/// noisy.
+const RATE_SMOOTHING_TIME_CONSTANT: f32 = 1.0;
+
+/// Smooths a [`ProgressBar`]'s visual fill toward a `target` fraction instead
+/// of jumping to it instantly, and tracks a rolling progress-per-second
+/// estimate so callers can show e.g. "loading, ~3s left".
+///
+/// Put this alongside a [`ProgressBar`]; `update_progress_animation` moves
+/// `displayed` toward `target` every frame and writes `displayed` (not the
+/// bar's raw progress) into the material.
+#[derive(Component, Clone)]
+pub struct ProgressAnimation {
+    /// The fraction `displayed` animates toward, in `[0.0, 1.0]`
+    target: f32,
+    /// The currently rendered fraction, in `[0.0, 1.0]`
+    displayed: f32,
+    /// How fast `displayed` moves toward `target`, in units/sec
+    pub speed: f32,
+    /// Exponentially-weighted estimate of `target`'s rate of change, in units/sec
+    rate: f32,
+    /// `target` as of the previous frame, used to compute the instantaneous rate
+    last_target: f32,
+    /// Total time this component has been updated
+    elapsed: Duration,
+}
+
+impl ProgressAnimation {
+    /// Creates a new animation with the given `speed` (in fraction-units/sec)
+    pub fn new(speed: f32) -> Self {
+        Self {
+            target: 0.0,
+            displayed: 0.0,
+            speed,
+            rate: 0.0,
+            last_target: 0.0,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Sets the fraction `displayed` should animate toward, clamped to `[0.0, 1.0]`
+    pub fn set_target(&mut self, target: f32) -> &mut Self {
+        self.target = target.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the fraction `displayed` is animating toward
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    /// Returns the fraction currently being rendered
+    pub fn displayed(&self) -> f32 {
+        self.displayed
+    }
+
+    /// Returns the current rolling estimate of `target`'s rate of change, in
+    /// fraction-units/sec. Decays toward `0.0` when `target` stops moving.
+    pub fn per_sec(&self) -> f32 {
+        self.rate
+    }
+
+    /// Estimated time until `target` reaches `1.0`: the remaining fraction
+    /// `1.0 - target` divided by `per_sec()`. Returns `Some(Duration::ZERO)`
+    /// once `target` is `1.0`, and `None` if the rate is zero or negative
+    /// (stalled or regressing) or so small that the estimate does not fit in
+    /// a `Duration`, so the ETA is never negative or infinite.
+    ///
+    /// # Examples
+    /// ```
+    /// use bevy_progressbar::ProgressAnimation;
+    ///
+    /// // A freshly created animation has no rate estimate yet, so there's
+    /// // nothing to extrapolate an ETA from.
+    /// let anim = ProgressAnimation::new(1.0);
+    /// assert_eq!(anim.eta(), None);
+    /// ```
+    pub fn eta(&self) -> Option<Duration> {
+        if self.rate <= 0.0 {
+            return None;
+        }
+        let remaining = (1.0 - self.target).max(0.0);
+        if remaining <= 0.0 {
+            return Some(Duration::ZERO);
+        }
+        Duration::try_from_secs_f32(remaining / self.rate).ok()
+    }
+
+    /// Total time this animation has been running
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Advances the animation by `dt`: moves `displayed` toward `target` by
+    /// at most `speed * dt.as_secs_f32()`, and folds the latest `target`
+    /// movement into the `per_sec()` rate estimate. `update_progress_animation`
+    /// calls this every frame with the engine's frame delta.
+    pub fn step(&mut self, dt: Duration) {
+        self.elapsed += dt;
+        let dt_secs = dt.as_secs_f32();
+
+        if dt_secs > 0.0 {
+            let instantaneous_rate = (self.target - self.last_target) / dt_secs;
+            let decay = (-dt_secs / RATE_SMOOTHING_TIME_CONSTANT).exp();
+            self.rate = self.rate * decay + instantaneous_rate * (1.0 - decay);
+        }
+        self.last_target = self.target;
+
+        let delta = self.target - self.displayed;
+        let max_step = self.speed * dt_secs;
+        self.displayed += delta.clamp(-max_step, max_step);
+    }
+}
+
+fn update_progress_animation(
+    time: Res<Time>,
+    mut bar_query: Query<(&mut ProgressAnimation, &MaterialNode<ProgressBarMaterial>)>,
+    mut materials: ResMut<Assets<ProgressBarMaterial>>,
+) {
+    for (mut anim, handle) in bar_query.iter_mut() {
+        anim.step(time.delta());
+
+        if let Some(material) = materials.get_mut(handle) {
+            material.progress = anim.displayed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod progress_animation_tests {
+    use super::ProgressAnimation;
+    use core::time::Duration;
+
+    #[test]
+    fn displayed_converges_to_target_without_overshoot() {
+        let mut anim = ProgressAnimation::new(0.5);
+        anim.set_target(1.0);
+
+        for _ in 0..1000 {
+            anim.step(Duration::from_millis(16));
+            assert!(anim.displayed() <= anim.target());
+        }
+
+        assert!((anim.displayed() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn rate_decays_toward_zero_when_target_stops_changing() {
+        let mut anim = ProgressAnimation::new(10.0);
+        let dt = Duration::from_millis(100);
+
+        // `target` moves steadily for a while, building up a positive rate.
+        for _ in 0..10 {
+            anim.set_target(anim.target() + 0.05);
+            anim.step(dt);
+        }
+        let rate_while_moving = anim.per_sec();
+        assert!(rate_while_moving > 0.0);
+
+        // `target` stops moving; the rate estimate should decay toward 0.0
+        // instead of staying pinned at its last value.
+        for _ in 0..50 {
+            anim.step(dt);
+        }
+        assert!(anim.per_sec() < rate_while_moving);
+        assert!(anim.per_sec().abs() < 0.01);
     }
 }